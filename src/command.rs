@@ -0,0 +1,24 @@
+//! Typed xdotool sub-commands, rendered into the command line by [`XServer::run`](crate::XServer::run).
+
+use std::fmt;
+
+pub mod options;
+pub mod sub_commands;
+
+/// Which xdotool sub-command to run.
+#[derive(Debug, Clone)]
+pub enum Command {
+    Desktop(sub_commands::Desktop),
+    Window(sub_commands::Window),
+    Misc(sub_commands::Misc),
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Desktop(d) => write!(f, "{d}"),
+            Command::Window(w) => write!(f, "{w}"),
+            Command::Misc(m) => write!(f, "{m}"),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Flags accepted by specific xdotool sub-commands.
+
+use std::fmt;
+
+/// Options for [`Desktop::SetDesktop`](crate::command::sub_commands::Desktop::SetDesktop).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetDesktopOption {
+    /// Use relative movement instead of absolute.
+    Relative,
+}
+
+impl fmt::Display for SetDesktopOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetDesktopOption::Relative => write!(f, "--relative"),
+        }
+    }
+}
+
+/// Options accepted by sync-capable sub-commands, e.g.
+/// [`Desktop::WindowActivate`](crate::command::sub_commands::Desktop::WindowActivate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncOption {
+    /// Wait until the action has completed before returning.
+    Sync,
+}
+
+impl fmt::Display for SyncOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncOption::Sync => write!(f, "--sync"),
+        }
+    }
+}
+
+/// Options for [`Window::Search`](crate::command::sub_commands::Window::Search).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchOption {
+    /// Match against the window name.
+    Name,
+    /// Match against the window class.
+    Class,
+}
+
+impl fmt::Display for SearchOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchOption::Name => write!(f, "--name"),
+            SearchOption::Class => write!(f, "--class"),
+        }
+    }
+}
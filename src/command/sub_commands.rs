@@ -0,0 +1,80 @@
+//! The individual sub-commands within each xdotool command group.
+
+use std::fmt;
+
+use crate::command::options::{SearchOption, SetDesktopOption, SyncOption};
+use crate::optionvec::OptionVec;
+
+/// `xdotool` desktop/workspace/viewport sub-commands.
+#[derive(Debug, Clone)]
+pub enum Desktop {
+    WindowActivate(OptionVec<SyncOption>),
+    GetActiveWindow,
+    SetNumDesktops,
+    GetNumDesktops,
+    SetDesktopViewport,
+    GetDesktopViewport,
+    SetDesktop(OptionVec<SetDesktopOption>),
+    GetDesktop,
+    SetDesktopForWindow,
+    GetDesktopForWindow,
+    /// `get_desktop_geometry` - the total `_NET_DESKTOP_GEOMETRY` pixel size spanned by all viewports.
+    GetDesktopGeometry,
+}
+
+impl fmt::Display for Desktop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Desktop::WindowActivate(o) => write!(f, "windowactivate {o}"),
+            Desktop::GetActiveWindow => write!(f, "getactivewindow"),
+            Desktop::SetNumDesktops => write!(f, "set_num_desktops"),
+            Desktop::GetNumDesktops => write!(f, "get_num_desktops"),
+            Desktop::SetDesktopViewport => write!(f, "set_desktop_viewport"),
+            Desktop::GetDesktopViewport => write!(f, "get_desktop_viewport"),
+            Desktop::SetDesktop(o) => write!(f, "set_desktop {o}"),
+            Desktop::GetDesktop => write!(f, "get_desktop"),
+            Desktop::SetDesktopForWindow => write!(f, "set_desktop_for_window"),
+            Desktop::GetDesktopForWindow => write!(f, "get_desktop_for_window"),
+            Desktop::GetDesktopGeometry => write!(f, "get_desktop_geometry"),
+        }
+    }
+}
+
+/// `xdotool` window sub-commands.
+#[derive(Debug, Clone)]
+pub enum Window {
+    Search(OptionVec<SearchOption>),
+    Focus,
+    Move,
+    Resize,
+    GetWindowName,
+    GetWindowClassName,
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Window::Search(o) => write!(f, "search {o}"),
+            Window::Focus => write!(f, "windowfocus"),
+            Window::Move => write!(f, "windowmove"),
+            Window::Resize => write!(f, "windowsize"),
+            Window::GetWindowName => write!(f, "getwindowname"),
+            Window::GetWindowClassName => write!(f, "getwindowclassname"),
+        }
+    }
+}
+
+/// Miscellaneous top-level `xdotool` sub-commands.
+#[derive(Debug, Clone)]
+pub enum Misc {
+    /// `getdisplaygeometry` - the per-monitor screen resolution.
+    GetDisplayGeometry,
+}
+
+impl fmt::Display for Misc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Misc::GetDisplayGeometry => write!(f, "getdisplaygeometry"),
+        }
+    }
+}
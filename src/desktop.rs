@@ -1,12 +1,67 @@
 //! Convenience functions for the desktop functionality in xdotool.
 
+use std::fmt;
 use std::process::Output;
+use std::string::FromUtf8Error;
 
 use crate::command::options::{SetDesktopOption, SyncOption};
 use crate::command::{sub_commands, Command};
 use crate::optionvec::OptionVec;
 use crate::XServer;
 
+/// Error returned by the typed desktop query functions, e.g. [`get_active_window_typed`](XServer::get_active_window_typed).
+#[derive(Debug)]
+pub enum DesktopQueryError {
+    /// The `xdotool` invocation exited with a non-zero status. Carries the captured stderr.
+    CommandFailed(String),
+    /// The command output was not valid UTF-8.
+    InvalidUtf8(FromUtf8Error),
+    /// The (trimmed) output could not be parsed into the expected value.
+    ParseFailed(String),
+}
+
+impl fmt::Display for DesktopQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DesktopQueryError::CommandFailed(stderr) => {
+                write!(f, "xdotool exited with an error: {stderr}")
+            }
+            DesktopQueryError::InvalidUtf8(e) => {
+                write!(f, "xdotool output was not valid UTF-8: {e}")
+            }
+            DesktopQueryError::ParseFailed(output) => {
+                write!(f, "could not parse xdotool output: {output:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DesktopQueryError {}
+
+impl From<FromUtf8Error> for DesktopQueryError {
+    fn from(e: FromUtf8Error) -> Self {
+        DesktopQueryError::InvalidUtf8(e)
+    }
+}
+
+/// Result type used by the typed desktop query functions.
+pub type Result<T> = std::result::Result<T, DesktopQueryError>;
+
+/// Turn a raw [`Output`] into the trimmed stdout string, failing on a non-zero exit status.
+pub(crate) fn output_to_string(output: Output) -> Result<String> {
+    if !output.status.success() {
+        return Err(DesktopQueryError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+pub(crate) fn parse_token<T: std::str::FromStr>(text: &str) -> Result<T> {
+    text.parse()
+        .map_err(|_| DesktopQueryError::ParseFailed(text.to_string()))
+}
+
 impl XServer {
     /// Activate the window. This command is different from [`focus_window`](../window/fn.focus_window.html): if the window is on another desktop, we will switch to that desktop.
     ///
@@ -44,6 +99,23 @@ impl XServer {
         self.run(c, "")
     }
 
+    /// Parsed variant of [`get_active_window`](XServer::get_active_window). Runs the same command
+    /// but decodes, trims and parses the output into a window id for you.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use xdotool::XServer;
+    /// # let server = XServer { display: 0, auth: String::new() };
+    /// let window_id = server.get_active_window_typed()?;
+    /// println!("{}", window_id);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get_active_window_typed(&self) -> Result<u64> {
+        let text = output_to_string(self.get_active_window())?;
+        parse_token(&text)
+    }
+
     /// Changes the number of desktops or workspaces.
     pub fn set_num_desktops(&self, num: u8) -> Output {
         let c = Command::Desktop(sub_commands::Desktop::SetNumDesktops);
@@ -65,6 +137,12 @@ impl XServer {
         self.run(c, "")
     }
 
+    /// Parsed variant of [`get_num_desktops`](XServer::get_num_desktops).
+    pub fn get_num_desktops_typed(&self) -> Result<u8> {
+        let text = output_to_string(self.get_num_desktops())?;
+        parse_token(&text)
+    }
+
     /// Move the viewport to the given position. Not all requests will be obeyed.
     pub fn set_desktop_viewport(&self, x: u16, y: u16) -> Output {
         let c = Command::Desktop(sub_commands::Desktop::SetDesktopViewport);
@@ -81,6 +159,20 @@ impl XServer {
         self.run(c, "")
     }
 
+    /// Parsed variant of [`get_desktop_viewport`](XServer::get_desktop_viewport). Parses the
+    /// `"<x> <y>"` output (e.g. `xdotool get_desktop_viewport` reporting `4780 0`) into a pixel pair.
+    pub fn get_desktop_viewport_typed(&self) -> Result<(u16, u16)> {
+        let text = output_to_string(self.get_desktop_viewport())?;
+        let mut tokens = text.split_whitespace();
+        let x = tokens
+            .next()
+            .ok_or_else(|| DesktopQueryError::ParseFailed(text.clone()))?;
+        let y = tokens
+            .next()
+            .ok_or_else(|| DesktopQueryError::ParseFailed(text.clone()))?;
+        Ok((parse_token(x)?, parse_token(y)?))
+    }
+
     /// Switch to the specified desktop.
     ///
     /// # Options
@@ -97,6 +189,12 @@ impl XServer {
         self.run(c, "")
     }
 
+    /// Parsed variant of [`get_desktop`](XServer::get_desktop).
+    pub fn get_desktop_typed(&self) -> Result<u8> {
+        let text = output_to_string(self.get_desktop())?;
+        parse_token(&text)
+    }
+
     /// Move a window to a different desktop.
     pub fn set_desktop_for_window(&self, window: &str, desktop_number: u8) -> Output {
         let c = Command::Desktop(sub_commands::Desktop::SetDesktopForWindow);
@@ -109,4 +207,54 @@ impl XServer {
         let c = Command::Desktop(sub_commands::Desktop::GetDesktopForWindow);
         self.run(c, window)
     }
+
+    /// Parsed variant of [`get_desktop_for_window`](XServer::get_desktop_for_window).
+    pub fn get_desktop_for_window_typed(&self, window: &str) -> Result<u8> {
+        let text = output_to_string(self.get_desktop_for_window(window))?;
+        parse_token(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::process::ExitStatusExt;
+
+    fn output(status: i32, stdout: &[u8], stderr: &[u8]) -> Output {
+        Output {
+            status: std::process::ExitStatus::from_raw(status),
+            stdout: stdout.to_vec(),
+            stderr: stderr.to_vec(),
+        }
+    }
+
+    #[test]
+    fn output_to_string_trims_successful_stdout() {
+        let text = output_to_string(output(0, b"  4780 0  \n", b"")).unwrap();
+        assert_eq!(text, "4780 0");
+    }
+
+    #[test]
+    fn output_to_string_reports_command_failed_on_nonzero_exit() {
+        let err = output_to_string(output(1, b"", b"no such window")).unwrap_err();
+        assert!(matches!(err, DesktopQueryError::CommandFailed(stderr) if stderr == "no such window"));
+    }
+
+    #[test]
+    fn output_to_string_reports_invalid_utf8() {
+        let err = output_to_string(output(0, &[0xff, 0xfe], b"")).unwrap_err();
+        assert!(matches!(err, DesktopQueryError::InvalidUtf8(_)));
+    }
+
+    #[test]
+    fn parse_token_parses_a_valid_token() {
+        let value: u8 = parse_token("7").unwrap();
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn parse_token_reports_parse_failed_on_non_numeric_text() {
+        let err = parse_token::<u8>("not-a-number").unwrap_err();
+        assert!(matches!(err, DesktopQueryError::ParseFailed(text) if text == "not-a-number"));
+    }
 }
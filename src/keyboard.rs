@@ -0,0 +1 @@
+//! Convenience functions for the keyboard functionality in xdotool.
@@ -19,6 +19,7 @@ pub mod keyboard;
 pub mod misc;
 pub mod mouse;
 pub mod optionvec;
+pub mod viewport;
 pub mod window;
 
 pub use optionvec::OptionVec;
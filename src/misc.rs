@@ -0,0 +1 @@
+//! Convenience functions for the miscellaneous top-level functionality in xdotool.
@@ -0,0 +1 @@
+//! Convenience functions for the mouse functionality in xdotool.
@@ -0,0 +1,46 @@
+//! A list of command options, rendered as space-separated flags.
+
+use std::fmt;
+
+/// A list of options for a sub-command, rendered as space-separated flags when a command is run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionVec<T>(Vec<T>);
+
+impl<T> OptionVec<T> {
+    /// Create an empty option list.
+    pub fn new() -> Self {
+        OptionVec(Vec::new())
+    }
+
+    /// Append an option.
+    pub fn push(&mut self, option: T) {
+        self.0.push(option);
+    }
+}
+
+impl<T> Default for OptionVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for OptionVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        OptionVec(iter.into_iter().collect())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for OptionVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered: Vec<String> = self.0.iter().map(|o| o.to_string()).collect();
+        write!(f, "{}", rendered.join(" "))
+    }
+}
+
+/// Build an [`OptionVec`] from a list of options, mirroring `vec![]`.
+#[macro_export]
+macro_rules! option_vec {
+    ($($x:expr),* $(,)?) => {
+        $crate::OptionVec::from_iter([$($x),*])
+    };
+}
@@ -0,0 +1,245 @@
+//! Mapping xdotool's raw viewport pixel offsets onto a stable grid, for window managers
+//! (Compiz, Unity, ...) that lay desktops out as viewports onto one large virtual screen
+//! instead of independent workspaces.
+
+use crate::command::{sub_commands, Command};
+use crate::desktop::{self, DesktopQueryError};
+use crate::XServer;
+
+/// A single cell of the viewport grid, resolved from the current pixel offset and the
+/// per-monitor screen geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    /// Zero-based column of this cell.
+    pub col: usize,
+    /// Zero-based row of this cell.
+    pub row: usize,
+    /// Number of columns in the grid.
+    pub cols: usize,
+    /// Linear index of this cell, `row * cols + col`. This is the value handed to
+    /// [`dispatch_by_viewport`](XServer::dispatch_by_viewport).
+    pub index: usize,
+}
+
+/// The per-monitor screen size and the total virtual desktop size it's tiled into, bundled so
+/// the viewport math doesn't need to take each dimension as a separate argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Geometry {
+    screen_w: u16,
+    screen_h: u16,
+    total_w: u16,
+    total_h: u16,
+}
+
+impl Geometry {
+    /// Number of columns/rows in the viewport grid implied by this geometry.
+    fn grid(&self) -> (i32, i32) {
+        let cols = (self.total_w.max(1) / self.screen_w.max(1)) as i32;
+        let rows = (self.total_h.max(1) / self.screen_h.max(1)) as i32;
+        (cols, rows)
+    }
+}
+
+fn parse_pixel_pair(text: &str) -> desktop::Result<(u16, u16)> {
+    let mut tokens = text.split_whitespace();
+    let x = tokens
+        .next()
+        .ok_or_else(|| DesktopQueryError::ParseFailed(text.to_string()))?;
+    let y = tokens
+        .next()
+        .ok_or_else(|| DesktopQueryError::ParseFailed(text.to_string()))?;
+    Ok((desktop::parse_token(x)?, desktop::parse_token(y)?))
+}
+
+/// Resolve `(vx, vy)` against `geometry` into a grid cell. Pure function of the geometry, so the
+/// truncation and 1x1 edge cases can be tested without shelling out to `xdotool`. No clamping
+/// happens here: an offset past the total width yields a `col` of `cols` or more.
+fn compute_viewport(vx: u16, vy: u16, geometry: Geometry) -> Viewport {
+    let screen_w = geometry.screen_w.max(1) as usize;
+    let screen_h = geometry.screen_h.max(1) as usize;
+    let total_w = geometry.total_w.max(1) as usize;
+
+    let col = vx as usize / screen_w;
+    let row = vy as usize / screen_h;
+    let cols = (total_w / screen_w).max(1);
+    let index = row * cols + col;
+
+    Viewport {
+        col,
+        row,
+        cols,
+        index,
+    }
+}
+
+/// Step `current` by `(dx, dy)` screens within the grid implied by `geometry`, wrapping or
+/// clamping at the edges, then convert the resulting cell back to a pixel offset.
+fn compute_move(current: Viewport, dx: i32, dy: i32, geometry: Geometry, wrap: bool) -> (u16, u16) {
+    let (cols, rows) = geometry.grid();
+    let cols = cols.max(1);
+    let rows = rows.max(1);
+
+    let mut col = current.col as i32 + dx;
+    let mut row = current.row as i32 + dy;
+    if wrap {
+        col = col.rem_euclid(cols);
+        row = row.rem_euclid(rows);
+    } else {
+        col = col.clamp(0, cols - 1);
+        row = row.clamp(0, rows - 1);
+    }
+
+    let x = col as u32 * geometry.screen_w.max(1) as u32;
+    let y = row as u32 * geometry.screen_h.max(1) as u32;
+    (x as u16, y as u16)
+}
+
+impl XServer {
+    /// Report the per-monitor screen resolution `(W, H)`, via `xdotool getdisplaygeometry`.
+    fn get_screen_geometry(&self) -> desktop::Result<(u16, u16)> {
+        let c = Command::Misc(sub_commands::Misc::GetDisplayGeometry);
+        let text = desktop::output_to_string(self.run(c, ""))?;
+        parse_pixel_pair(&text)
+    }
+
+    /// Report the full virtual desktop geometry (`_NET_DESKTOP_GEOMETRY`), i.e. the total pixel
+    /// size spanned by all viewports, via `xdotool get_desktop_geometry`.
+    fn get_total_desktop_geometry(&self) -> desktop::Result<(u16, u16)> {
+        let c = Command::Desktop(sub_commands::Desktop::GetDesktopGeometry);
+        let text = desktop::output_to_string(self.run(c, ""))?;
+        parse_pixel_pair(&text)
+    }
+
+    /// Query the current viewport offset together with the screen and total desktop geometry in
+    /// one go, so callers needing more than the resolved [`Viewport`] (e.g.
+    /// [`move_viewport`](XServer::move_viewport)) don't have to re-query it.
+    fn query_viewport_geometry(&self) -> desktop::Result<(Viewport, Geometry)> {
+        let (vx, vy) = self.get_desktop_viewport_typed()?;
+        let (screen_w, screen_h) = self.get_screen_geometry()?;
+        let (total_w, total_h) = self.get_total_desktop_geometry()?;
+        let geometry = Geometry {
+            screen_w,
+            screen_h,
+            total_w,
+            total_h,
+        };
+        let viewport = compute_viewport(vx, vy, geometry);
+        Ok((viewport, geometry))
+    }
+
+    /// Resolve the viewport grid cell the desktop is currently showing.
+    ///
+    /// Reads the current pixel offset via
+    /// [`get_desktop_viewport_typed`](XServer::get_desktop_viewport_typed), the full desktop
+    /// geometry, and the per-monitor screen size `(W, H)`, then computes `col = vx / W`,
+    /// `row = vy / H` and `cols = total_width / W`. Offsets that aren't an exact multiple of
+    /// `W`/`H` are integer-truncated down to the containing cell. Window managers that don't
+    /// support viewports report a `1x1` grid, i.e. `cols = 1` and `index = 0`.
+    pub fn current_viewport(&self) -> desktop::Result<Viewport> {
+        Ok(self.query_viewport_geometry()?.0)
+    }
+
+    /// Convenience wrapper around [`current_viewport`](XServer::current_viewport) returning just
+    /// the linear cell index.
+    pub fn current_viewport_cell(&self) -> desktop::Result<usize> {
+        Ok(self.current_viewport()?.index)
+    }
+
+    /// Invoke the handler matching the current viewport cell.
+    ///
+    /// If the current cell index is out of range for `handlers` (e.g. because the window
+    /// manager reports more viewports than the caller provided handlers for), it is clamped to
+    /// the last handler.
+    pub fn dispatch_by_viewport(&self, handlers: &[&dyn Fn()]) -> desktop::Result<()> {
+        if let Some(last) = handlers.len().checked_sub(1) {
+            let cell = self.current_viewport_cell()?.min(last);
+            handlers[cell]();
+        }
+        Ok(())
+    }
+
+    /// Move the viewport relative to its current position, in units of screen width/height.
+    /// `wrap` controls whether stepping past the last column/row wraps back around to the first,
+    /// or clamps at the grid boundary.
+    pub fn move_viewport(
+        &self,
+        dx: i32,
+        dy: i32,
+        wrap: bool,
+    ) -> desktop::Result<std::process::Output> {
+        let (current, geometry) = self.query_viewport_geometry()?;
+        let (x, y) = compute_move(current, dx, dy, geometry, wrap);
+        Ok(self.set_desktop_viewport(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDE: Geometry = Geometry {
+        screen_w: 1280,
+        screen_h: 1024,
+        total_w: 3840,
+        total_h: 1024,
+    };
+
+    const SINGLE: Geometry = Geometry {
+        screen_w: 1280,
+        screen_h: 1024,
+        total_w: 1280,
+        total_h: 1024,
+    };
+
+    #[test]
+    fn truncates_offsets_that_are_not_exact_multiples() {
+        let viewport = compute_viewport(1279, 0, WIDE);
+        assert_eq!(viewport.col, 0);
+        assert_eq!(viewport.row, 0);
+        assert_eq!(viewport.index, 0);
+    }
+
+    #[test]
+    fn resolves_a_cell_in_a_multi_column_row() {
+        let viewport = compute_viewport(2560, 1024, WIDE);
+        assert_eq!(viewport.col, 2);
+        assert_eq!(viewport.row, 1);
+        assert_eq!(viewport.cols, 3);
+        assert_eq!(viewport.index, 5);
+    }
+
+    #[test]
+    fn one_by_one_grid_when_wm_does_not_support_viewports() {
+        let viewport = compute_viewport(0, 0, SINGLE);
+        assert_eq!(viewport.cols, 1);
+        assert_eq!(viewport.index, 0);
+    }
+
+    #[test]
+    fn clamps_out_of_range_index_at_grid_boundary() {
+        let current = compute_viewport(2560, 0, WIDE);
+        let (x, y) = compute_move(current, 5, 0, WIDE, false);
+        assert_eq!((x, y), (2560, 0));
+    }
+
+    #[test]
+    fn negative_delta_steps_left() {
+        let current = compute_viewport(2560, 0, WIDE);
+        let (x, y) = compute_move(current, -1, 0, WIDE, false);
+        assert_eq!((x, y), (1280, 0));
+    }
+
+    #[test]
+    fn wraps_past_the_last_column_back_to_the_first() {
+        let current = compute_viewport(2560, 0, WIDE);
+        let (x, y) = compute_move(current, 1, 0, WIDE, true);
+        assert_eq!((x, y), (0, 0));
+    }
+
+    #[test]
+    fn wraps_a_negative_delta_past_the_first_column_to_the_last() {
+        let current = compute_viewport(0, 0, WIDE);
+        let (x, y) = compute_move(current, -1, 0, WIDE, true);
+        assert_eq!((x, y), (2560, 0));
+    }
+}
@@ -0,0 +1,147 @@
+//! An owned, chainable window handle built on top of the per-window sub-commands.
+
+use regex::Regex;
+
+use crate::command::options::{SearchOption, SyncOption};
+use crate::command::{sub_commands, Command};
+use crate::desktop::{self, DesktopQueryError};
+use crate::optionvec::OptionVec;
+use crate::XServer;
+
+/// A window, identified by its xdotool id, bound to the [`XServer`] that resolved it.
+pub struct Window<'a> {
+    id: u64,
+    server: &'a XServer,
+}
+
+impl<'a> Window<'a> {
+    /// Wrap an already-known window id.
+    pub fn new(server: &'a XServer, id: u64) -> Self {
+        Window { id, server }
+    }
+
+    /// The underlying xdotool window id.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Activate the window, switching to its desktop first if necessary. See
+    /// [`XServer::activate_window`].
+    pub fn activate(&self, options: OptionVec<SyncOption>) -> std::process::Output {
+        self.server.activate_window(&self.id.to_string(), options)
+    }
+
+    /// Report the desktop this window currently lives on.
+    ///
+    /// Depends on window-manager EWMH support; a non-zero `xdotool` exit is surfaced as an
+    /// [`Err`].
+    pub fn desktop(&self) -> crate::desktop::Result<u8> {
+        self.server
+            .get_desktop_for_window_typed(&self.id.to_string())
+    }
+
+    /// Move the window to the given desktop.
+    ///
+    /// Depends on window-manager EWMH support; a non-zero `xdotool` exit is surfaced as an
+    /// [`Err`].
+    pub fn move_to_desktop(&self, desktop_number: u8) -> crate::desktop::Result<()> {
+        let output = self
+            .server
+            .set_desktop_for_window(&self.id.to_string(), desktop_number);
+        if !output.status.success() {
+            return Err(DesktopQueryError::CommandFailed(
+                String::from_utf8_lossy(&output.stderr).into_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Give the window input focus, without switching desktops or raising it.
+    pub fn focus(&self) -> std::process::Output {
+        let c = Command::Window(sub_commands::Window::Focus);
+        self.server.run(c, &self.id.to_string())
+    }
+
+    /// Move the window so its top-left corner is at `(x, y)`.
+    pub fn move_window(&self, x: i32, y: i32) -> std::process::Output {
+        let c = Command::Window(sub_commands::Window::Move);
+        let args = format!("{} {} {}", self.id, x, y);
+        self.server.run(c, &args)
+    }
+
+    /// Resize the window to `width` x `height`.
+    pub fn resize(&self, width: u32, height: u32) -> std::process::Output {
+        let c = Command::Window(sub_commands::Window::Resize);
+        let args = format!("{} {} {}", self.id, width, height);
+        self.server.run(c, &args)
+    }
+}
+
+impl XServer {
+    /// Output the name of the given window.
+    pub fn get_window_name(&self, window: &str) -> std::process::Output {
+        let c = Command::Window(sub_commands::Window::GetWindowName);
+        self.run(c, window)
+    }
+
+    /// Output the class of the given window.
+    pub fn get_window_class_name(&self, window: &str) -> std::process::Output {
+        let c = Command::Window(sub_commands::Window::GetWindowClassName);
+        self.run(c, window)
+    }
+
+    /// Search for windows, parsing the newline-separated id list `xdotool search` prints rather
+    /// than leaving every caller to decode and split stdout themselves.
+    ///
+    /// See [`SearchOption`](crate::command::options::SearchOption) for the available flags (name,
+    /// class, ...).
+    pub fn search_windows(
+        &self,
+        pattern: &str,
+        options: OptionVec<SearchOption>,
+    ) -> desktop::Result<Vec<u64>> {
+        let c = Command::Window(sub_commands::Window::Search(options));
+        let text = desktop::output_to_string(self.run(c, pattern))?;
+        text.lines()
+            .filter(|line| !line.is_empty())
+            .map(desktop::parse_token)
+            .collect()
+    }
+
+    /// Like [`search_windows`](XServer::search_windows), but also filters the matches by running
+    /// `name_regex` against each window's name (fetched via
+    /// [`get_window_name`](XServer::get_window_name)) or class (fetched via
+    /// [`get_window_class_name`](XServer::get_window_class_name)).
+    pub fn search_windows_matching(
+        &self,
+        pattern: &str,
+        options: OptionVec<SearchOption>,
+        name_regex: &Regex,
+    ) -> desktop::Result<Vec<u64>> {
+        let ids = self.search_windows(pattern, options)?;
+        let mut matching = Vec::new();
+        for id in ids {
+            let name = desktop::output_to_string(self.get_window_name(&id.to_string()))?;
+            let class = desktop::output_to_string(self.get_window_class_name(&id.to_string()))?;
+            if name_regex.is_match(&name) || name_regex.is_match(&class) {
+                matching.push(id);
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Return the active window as an owned, chainable [`Window`] handle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use xdotool::XServer;
+    /// # let server = XServer { display: 0, auth: String::new() };
+    /// server.active_window()?.move_to_desktop(2)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn active_window(&self) -> crate::desktop::Result<Window<'_>> {
+        let id = self.get_active_window_typed()?;
+        Ok(Window::new(self, id))
+    }
+}